@@ -1,10 +1,12 @@
 use clap::Parser;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use syn::{File, Item};
+use syn::punctuated::Punctuated;
+use syn::{File, GenericArgument, Item, ItemStruct, Meta, PathArguments, Token, Type};
 
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
@@ -15,89 +17,297 @@ struct Cli {
     /// Output path for the generated IDL file
     #[clap(short, long, default_value = "idl.json")]
     output: PathBuf,
+
+    /// Skip collecting doc comments, for smaller output
+    #[clap(long)]
+    no_docs: bool,
+
+    /// Also emit a TypeScript type + const declaration at this path
+    #[clap(long)]
+    out_ts: Option<PathBuf>,
+
+    /// IDL generation backend: `parse` reads the `cargo expand`ed source with syn
+    #[clap(long, value_enum, default_value_t = Mode::Parse)]
+    mode: Mode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum Mode {
+    Parse,
+    /// Not implemented yet — always returns an error. Hidden from `--help`
+    /// so it isn't advertised as a working alternative to `parse`.
+    #[value(hide = true)]
+    Build,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct Idl {
     version: String,
     name: String,
     instructions: Vec<IdlInstruction>,
     accounts: Vec<IdlAccount>,
     types: Vec<IdlType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<IdlErrorCode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct IdlInstruction {
     name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
     args: Vec<IdlArgument>,
     accounts: Vec<IdlAccountMeta>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct IdlArgument {
     name: String,
-    type_name: String,
+    #[serde(rename = "type")]
+    type_name: IdlTypeNode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct IdlAccountMeta {
     name: String,
     is_mut: bool,
     is_signer: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pda: Option<IdlPda>,
+}
+
+/// A PDA derivation recipe for an account constrained with `seeds = [...]`.
+#[derive(Serialize, Deserialize)]
+struct IdlPda {
+    seeds: Vec<IdlSeed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    program: Option<IdlSeed>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum IdlSeed {
+    Const { value: Vec<u8> },
+    Account { path: String },
+    Arg { path: String },
+    /// The program's own address, e.g. a `crate::ID` or `<Type>::id()` seed.
+    Program,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct IdlAccount {
     name: String,
     type_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
     fields: Vec<IdlField>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct IdlField {
     name: String,
-    type_name: String,
+    #[serde(rename = "type")]
+    type_name: IdlTypeNode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
+}
+
+/// A recursive IDL type description, serialized to Anchor's canonical shape
+/// (e.g. `{"kind":"u64"}`, `{"vec":{"kind":"pubkey"}}`, `{"defined":"Foo"}`)
+/// instead of the raw stringified Rust type.
+#[derive(Debug, PartialEq)]
+enum IdlTypeNode {
+    Primitive(&'static str),
+    Vec(Box<IdlTypeNode>),
+    Option(Box<IdlTypeNode>),
+    Array(Box<IdlTypeNode>, usize),
+    Defined(String),
 }
 
-#[derive(Serialize)]
+impl Serialize for IdlTypeNode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            IdlTypeNode::Primitive(kind) => map.serialize_entry("kind", kind)?,
+            IdlTypeNode::Vec(inner) => map.serialize_entry("vec", inner)?,
+            IdlTypeNode::Option(inner) => map.serialize_entry("option", inner)?,
+            IdlTypeNode::Array(inner, len) => map.serialize_entry("array", &(inner, len))?,
+            IdlTypeNode::Defined(name) => map.serialize_entry("defined", name)?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for IdlTypeNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        idl_type_node_from_json(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Mirrors [`IdlTypeNode`]'s `Serialize` impl in reverse, so IDL JSON written
+/// by this tool deserializes back into the same type tree.
+fn idl_type_node_from_json(value: &serde_json::Value) -> Result<IdlTypeNode, String> {
+    let obj = value
+        .as_object()
+        .ok_or_else(|| "expected an object for IdlTypeNode".to_string())?;
+
+    if let Some(kind) = obj.get("kind").and_then(|v| v.as_str()) {
+        return primitive_kind_from_str(kind)
+            .map(IdlTypeNode::Primitive)
+            .ok_or_else(|| format!("unknown IdlTypeNode kind: {kind}"));
+    }
+    if let Some(inner) = obj.get("vec") {
+        return Ok(IdlTypeNode::Vec(Box::new(idl_type_node_from_json(inner)?)));
+    }
+    if let Some(inner) = obj.get("option") {
+        return Ok(IdlTypeNode::Option(Box::new(idl_type_node_from_json(inner)?)));
+    }
+    if let Some(array) = obj.get("array").and_then(|v| v.as_array()) {
+        let [inner, len] = array.as_slice() else {
+            return Err("expected a 2-element [type, len] array".to_string());
+        };
+        let len = len
+            .as_u64()
+            .ok_or_else(|| "expected an array length".to_string())? as usize;
+        return Ok(IdlTypeNode::Array(Box::new(idl_type_node_from_json(inner)?), len));
+    }
+    if let Some(name) = obj.get("defined").and_then(|v| v.as_str()) {
+        return Ok(IdlTypeNode::Defined(name.to_string()));
+    }
+
+    Err("unrecognized IdlTypeNode shape".to_string())
+}
+
+fn primitive_kind_from_str(kind: &str) -> Option<&'static str> {
+    match kind {
+        "bool" => Some("bool"),
+        "u8" => Some("u8"),
+        "i8" => Some("i8"),
+        "u16" => Some("u16"),
+        "i16" => Some("i16"),
+        "u32" => Some("u32"),
+        "i32" => Some("i32"),
+        "u64" => Some("u64"),
+        "i64" => Some("i64"),
+        "u128" => Some("u128"),
+        "i128" => Some("i128"),
+        "pubkey" => Some("pubkey"),
+        "string" => Some("string"),
+        _ => None,
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 struct IdlType {
     name: String,
     type_def: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docs: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IdlErrorCode {
+    code: u32,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    msg: Option<String>,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Cli::parse();
 
+    let idl = match args.mode {
+        Mode::Parse => build_idl_via_parse(&args)?,
+        Mode::Build => build_idl_via_build_mode(&args)?,
+    };
+
+    // Serialize to JSON
+    let idl_json = serde_json::to_string_pretty(&idl)?;
+    fs::write(&args.output, &idl_json)?;
+
+    println!("IDL generated at {}", args.output.display());
+
+    if let Some(out_ts) = &args.out_ts {
+        let ts = generate_typescript(&idl, &idl_json);
+        fs::write(out_ts, ts)?;
+        println!("TypeScript IDL generated at {}", out_ts.display());
+    }
+
+    Ok(())
+}
+
+/// Default backend: expands the crate with `cargo expand` and walks the
+/// resulting syntax tree with `syn`.
+fn build_idl_via_parse(args: &Cli) -> Result<Idl, Box<dyn std::error::Error>> {
     // Expand the entire crate
     let expanded_code = expand_crate(&args.manifest_path)?;
 
     // Parse the expanded code
     let ast = parse_source_file(&expanded_code)?;
 
+    // `#[error_code]` is an attribute macro: by the time `cargo expand` is
+    // done, it has consumed its own attribute along with every `#[msg(...)]`
+    // on the enum's variants, so error metadata has to come from the
+    // original, un-expanded source instead.
+    let source_code = read_crate_source(&args.manifest_path)?;
+    let source_ast = parse_source_file(&source_code)?;
+
     // Collect metadata
-    let instructions = collect_instructions(&ast);
-    let accounts = collect_accounts(&ast);
-    let types = collect_types(&ast);
+    let include_docs = !args.no_docs;
+    let account_structs = parse_account_derives(&ast);
+    let instructions = collect_instructions(&ast, &account_structs, include_docs);
+    let accounts = collect_accounts(&ast, include_docs);
+    let types = collect_types(&ast, include_docs, error_enum_name(&source_ast).as_deref());
+    let errors = collect_errors(&source_ast);
 
     // Extract program name from Cargo.toml
     let program_name = extract_program_name(&args.manifest_path)?;
 
-    // Create the IDL
-    let idl = Idl {
+    Ok(Idl {
         version: "0.1.0".to_string(),
         name: program_name,
         instructions,
         accounts,
         types,
-    };
-
-    // Serialize to JSON
-    let idl_json = serde_json::to_string_pretty(&idl)?;
-    fs::write(&args.output, idl_json)?;
-
-    println!("IDL generated at {}", args.output.display());
+        errors,
+        docs: doc_opt(&ast.attrs, include_docs),
+    })
+}
 
-    Ok(())
+/// Alternative backend (`--mode build`), intended for information that only
+/// exists after monomorphization (const generics, trait-provided
+/// discriminators, associated-const sizes), which `cargo expand` + `syn`
+/// can't see: compile the target program with an `IdlEmit`-style runtime
+/// helper enabled, run the resulting `__bulk_idl_dump` binary, and deserialize
+/// the `Idl` JSON it prints to stdout — the same struct the parse backend
+/// produces, so callers wouldn't need to care which mode generated it.
+///
+/// **Not implemented yet.** This crate doesn't ship the runtime helper
+/// (`IdlEmit` trait, `__print_idl()` codegen, `__bulk_idl_dump` binary, or the
+/// `build-idl` feature) that a target program would need to depend on, and
+/// has no `[lib]` target of its own to host one. Until that lands, fail fast
+/// with an explicit message rather than shelling out to a `cargo run` that
+/// can never succeed.
+fn build_idl_via_build_mode(_args: &Cli) -> Result<Idl, Box<dyn std::error::Error>> {
+    Err(Box::new(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "--mode build is not implemented yet: it requires a bulk-idl runtime helper crate \
+         (an `IdlEmit` trait, generated `__print_idl()`, and a `__bulk_idl_dump` binary behind \
+         a `build-idl` feature) that this crate does not yet provide. Use --mode parse instead.",
+    )))
 }
 
 fn expand_crate(manifest_path: &PathBuf) -> io::Result<String> {
@@ -123,13 +333,27 @@ fn parse_source_file(file_content: &str) -> syn::Result<File> {
     syn::parse_file(file_content)
 }
 
-fn collect_instructions(ast: &File) -> Vec<IdlInstruction> {
+/// Reads the crate's own `src/lib.rs`, un-expanded, next to its manifest.
+fn read_crate_source(manifest_path: &Path) -> io::Result<String> {
+    let lib_path = manifest_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("src")
+        .join("lib.rs");
+    fs::read_to_string(lib_path)
+}
+
+fn collect_instructions(
+    ast: &File,
+    account_structs: &HashMap<String, &ItemStruct>,
+    include_docs: bool,
+) -> Vec<IdlInstruction> {
     let mut instructions = Vec::new();
 
     for item in &ast.items {
         if let Item::Fn(item_fn) = item {
             if is_instruction_fn(item_fn) {
-                instructions.push(parse_instruction_fn(item_fn));
+                instructions.push(parse_instruction_fn(item_fn, account_structs, include_docs));
             }
         }
     }
@@ -141,45 +365,443 @@ fn is_instruction_fn(item_fn: &syn::ItemFn) -> bool {
     matches!(item_fn.vis, syn::Visibility::Public(_))
 }
 
-fn parse_instruction_fn(item_fn: &syn::ItemFn) -> IdlInstruction {
+fn parse_instruction_fn(
+    item_fn: &syn::ItemFn,
+    account_structs: &HashMap<String, &ItemStruct>,
+    include_docs: bool,
+) -> IdlInstruction {
     let name = item_fn.sig.ident.to_string();
+    let arg_names: Vec<String> = item_fn
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let mut accounts = Vec::new();
     let args = item_fn
         .sig
         .inputs
         .iter()
         .filter_map(|arg| match arg {
             syn::FnArg::Typed(pat_type) => {
+                if accounts.is_empty() {
+                    if let Some(ctx_name) = context_struct_name(&pat_type.ty) {
+                        if let Some(item_struct) = account_structs.get(&ctx_name) {
+                            accounts =
+                                parse_accounts_struct_fields(item_struct, account_structs, &arg_names);
+                        }
+                    }
+                }
+
                 let arg_name = match &*pat_type.pat {
                     syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
                     _ => "_".to_string(),
                 };
-                let type_name = type_to_string(&*pat_type.ty);
-                Some(IdlArgument { name: arg_name, type_name })
+                let type_name = type_to_idl_type_node(&*pat_type.ty);
+                let docs = doc_opt(&pat_type.attrs, include_docs);
+                Some(IdlArgument { name: arg_name, type_name, docs })
             }
             _ => None,
         })
         .collect();
 
-    let accounts = Vec::new(); // Collect accounts if possible
-
     IdlInstruction {
         name,
+        docs: doc_opt(&item_fn.attrs, include_docs),
         args,
         accounts,
     }
 }
 
+/// Builds a map from struct name to its definition for every struct carrying
+/// `#[derive(Accounts)]`, so instruction parsing can resolve a `Context<Xyz>`
+/// argument to its fields without caring where `Xyz` is declared in the file.
+fn parse_account_derives(ast: &File) -> HashMap<String, &ItemStruct> {
+    let mut structs = HashMap::new();
+
+    for item in &ast.items {
+        if let Item::Struct(item_struct) = item {
+            if is_accounts_derive_struct(item_struct) {
+                structs.insert(item_struct.ident.to_string(), item_struct);
+            }
+        }
+    }
+
+    structs
+}
+
+fn is_accounts_derive_struct(item_struct: &ItemStruct) -> bool {
+    item_struct.attrs.iter().any(|attr| {
+        if !attr.path().is_ident("derive") {
+            return false;
+        }
+        attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+            .map(|paths| paths.iter().any(|path| path.is_ident("Accounts")))
+            .unwrap_or(false)
+    })
+}
+
+/// If `ty` is (a reference to) `Context<'_, .., Xyz>`, returns `Xyz`'s name.
+fn context_struct_name(ty: &Type) -> Option<String> {
+    let ty = match ty {
+        Type::Reference(type_reference) => &*type_reference.elem,
+        other => other,
+    };
+
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Context" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return None;
+    };
+    generics.args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(Type::Path(inner_path)) => {
+            Some(inner_path.path.segments.last()?.ident.to_string())
+        }
+        _ => None,
+    })
+}
+
+/// Flattens an `#[derive(Accounts)]` struct into its `IdlAccountMeta` entries,
+/// recursing into composite fields whose type is itself an `Accounts` struct.
+fn parse_accounts_struct_fields(
+    item_struct: &ItemStruct,
+    account_structs: &HashMap<String, &ItemStruct>,
+    arg_names: &[String],
+) -> Vec<IdlAccountMeta> {
+    let mut metas = Vec::new();
+    let account_names: Vec<String> = item_struct
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+        .collect();
+
+    for field in &item_struct.fields {
+        let Some(field_name) = field.ident.as_ref().map(|ident| ident.to_string()) else {
+            continue;
+        };
+
+        if let Some(nested_name) = account_field_struct_name(&field.ty) {
+            if let Some(nested_struct) = account_structs.get(&nested_name) {
+                metas.extend(parse_accounts_struct_fields(
+                    nested_struct,
+                    account_structs,
+                    arg_names,
+                ));
+                continue;
+            }
+        }
+
+        let account_meta = field_account_meta(field);
+        metas.push(IdlAccountMeta {
+            name: field_name,
+            is_mut: account_meta.is_mut,
+            is_signer: account_meta.is_signer || field_type_is_signer(&field.ty),
+            pda: field_pda(field, &account_names, arg_names),
+        });
+    }
+
+    metas
+}
+
+/// Returns the last path segment of `ty`, which the caller looks up among the
+/// known `#[derive(Accounts)]` structs to flatten a composite account field.
+fn account_field_struct_name(ty: &Type) -> Option<String> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    Some(type_path.path.segments.last()?.ident.to_string())
+}
+
+fn field_type_is_signer(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Signer"),
+        _ => false,
+    }
+}
+
+struct FieldAccountFlags {
+    is_mut: bool,
+    is_signer: bool,
+}
+
+/// One constraint inside an `#[account(...)]` list. `mut` is carved out from
+/// the rest because it's a reserved keyword, not an identifier, so it can't
+/// parse as part of a plain `Meta::Path` alongside `signer` or
+/// `seeds = [...]` — and without this, parsing the *entire* constraint list
+/// fails as soon as `mut` appears anywhere in it.
+enum AccountConstraint {
+    Mut,
+    Meta(Box<Meta>),
+}
+
+impl syn::parse::Parse for AccountConstraint {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![mut]) {
+            input.parse::<Token![mut]>()?;
+            Ok(AccountConstraint::Mut)
+        } else {
+            input.parse().map(|meta| AccountConstraint::Meta(Box::new(meta)))
+        }
+    }
+}
+
+/// Parses `attr`'s `#[account(...)]` constraint list, or `None` if it isn't
+/// an `#[account(...)]` attribute or fails to parse.
+fn parse_account_constraints(attr: &syn::Attribute) -> Option<Vec<AccountConstraint>> {
+    if !attr.path().is_ident("account") {
+        return None;
+    }
+    attr.parse_args_with(Punctuated::<AccountConstraint, Token![,]>::parse_terminated)
+        .ok()
+        .map(|punctuated| punctuated.into_iter().collect())
+}
+
+/// Parses the `#[account(...)]` constraint list on an `Accounts` struct field,
+/// looking only for the bare `mut` / `signer` markers relevant to IDL metadata.
+fn field_account_meta(field: &syn::Field) -> FieldAccountFlags {
+    let mut flags = FieldAccountFlags {
+        is_mut: false,
+        is_signer: false,
+    };
+
+    for attr in &field.attrs {
+        let Some(constraints) = parse_account_constraints(attr) else {
+            continue;
+        };
+        for constraint in constraints {
+            match constraint {
+                AccountConstraint::Mut => flags.is_mut = true,
+                AccountConstraint::Meta(meta) => {
+                    if let Meta::Path(path) = meta.as_ref() {
+                        if path.is_ident("signer") {
+                            flags.is_signer = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    flags
+}
+
+/// Parses the `seeds = [...]` (and `seeds::program = ...`) constraint on an
+/// `Accounts` struct field into a PDA derivation description, so clients can
+/// derive the account automatically instead of requiring it as an argument.
+fn field_pda(field: &syn::Field, account_names: &[String], arg_names: &[String]) -> Option<IdlPda> {
+    let mut seeds = Vec::new();
+    let mut program = None;
+
+    for attr in &field.attrs {
+        let Some(constraints) = parse_account_constraints(attr) else {
+            continue;
+        };
+        for constraint in constraints {
+            let AccountConstraint::Meta(meta) = constraint else {
+                continue;
+            };
+            let Meta::NameValue(name_value) = meta.as_ref() else {
+                continue;
+            };
+            if name_value.path.is_ident("seeds") {
+                if let syn::Expr::Array(expr_array) = &name_value.value {
+                    seeds.extend(
+                        expr_array
+                            .elems
+                            .iter()
+                            .filter_map(|elem| seed_expr_to_idl_seed(elem, account_names, arg_names)),
+                    );
+                }
+            } else if is_seeds_program_path(&name_value.path) {
+                program = seed_expr_to_idl_seed(&name_value.value, account_names, arg_names);
+            }
+        }
+    }
+
+    (!seeds.is_empty()).then_some(IdlPda { seeds, program })
+}
+
+fn is_seeds_program_path(path: &syn::Path) -> bool {
+    path.segments.len() == 2
+        && path.segments[0].ident == "seeds"
+        && path.segments[1].ident == "program"
+}
+
+/// Classifies one seed expression: a byte literal becomes a const seed, the
+/// `crate::ID` / `<Type>::id()` idiom becomes a program seed, and a bare
+/// identifier (after peeling `.as_ref()` / `.key().as_ref()` chains) resolves
+/// to whichever of the account or instruction arg name lists it matches.
+/// Unrecognized shapes are dropped rather than guessed at.
+fn seed_expr_to_idl_seed(
+    expr: &syn::Expr,
+    account_names: &[String],
+    arg_names: &[String],
+) -> Option<IdlSeed> {
+    match expr {
+        syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            syn::Lit::ByteStr(byte_str) => Some(IdlSeed::Const {
+                value: byte_str.value(),
+            }),
+            _ => None,
+        },
+        syn::Expr::Array(expr_array) => {
+            let mut value = Vec::with_capacity(expr_array.elems.len());
+            for elem in &expr_array.elems {
+                let syn::Expr::Lit(expr_lit) = elem else {
+                    return None;
+                };
+                let syn::Lit::Int(lit_int) = &expr_lit.lit else {
+                    return None;
+                };
+                value.push(lit_int.base10_parse::<u8>().ok()?);
+            }
+            Some(IdlSeed::Const { value })
+        }
+        _ if is_program_id_expr(expr) => Some(IdlSeed::Program),
+        _ => {
+            let name = seed_base_ident(expr)?;
+            if account_names.contains(&name) {
+                Some(IdlSeed::Account { path: name })
+            } else if arg_names.contains(&name) {
+                Some(IdlSeed::Arg { path: name })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Recognizes the standard Anchor idioms for a program referencing its own
+/// address in a seed list: the `crate::ID` (or `some::path::ID`) constant,
+/// and a `<Type>::id()` call.
+fn is_program_id_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Path(expr_path) => expr_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "ID"),
+        syn::Expr::Call(expr_call) => match &*expr_call.func {
+            syn::Expr::Path(expr_path) => expr_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "id"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Peels `x.as_ref()` / `x.key().as_ref()` / `&x` chains down to the base
+/// identifier `x`.
+fn seed_base_ident(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(expr_path) => expr_path.path.get_ident().map(|ident| ident.to_string()),
+        syn::Expr::MethodCall(method_call) => seed_base_ident(&method_call.receiver),
+        syn::Expr::Reference(expr_reference) => seed_base_ident(&expr_reference.expr),
+        syn::Expr::Field(expr_field) => seed_base_ident(&expr_field.base),
+        _ => None,
+    }
+}
+
+/// Converts a `syn::Type` into its canonical `IdlTypeNode`, peeling references
+/// and known generic containers (`Vec`, `Option`, fixed-size arrays) and
+/// falling back to `Defined` for any other single-segment path.
+fn type_to_idl_type_node(ty: &Type) -> IdlTypeNode {
+    match ty {
+        Type::Reference(type_reference) => type_to_idl_type_node(&type_reference.elem),
+        Type::Array(type_array) => {
+            let inner = type_to_idl_type_node(&type_array.elem);
+            let len = match &type_array.len {
+                syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    syn::Lit::Int(lit_int) => lit_int.base10_parse::<usize>().unwrap_or(0),
+                    _ => 0,
+                },
+                _ => 0,
+            };
+            IdlTypeNode::Array(Box::new(inner), len)
+        }
+        Type::Path(type_path) => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return IdlTypeNode::Defined(String::new());
+            };
+            let ident = segment.ident.to_string();
+
+            if let PathArguments::AngleBracketed(generics) = &segment.arguments {
+                let inner_ty = generics.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(inner) => Some(inner),
+                    _ => None,
+                });
+                if let Some(inner_ty) = inner_ty {
+                    match ident.as_str() {
+                        "Vec" => {
+                            return IdlTypeNode::Vec(Box::new(type_to_idl_type_node(inner_ty)))
+                        }
+                        "Option" => {
+                            return IdlTypeNode::Option(Box::new(type_to_idl_type_node(inner_ty)))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            match ident.as_str() {
+                "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+                | "i128" => IdlTypeNode::Primitive(primitive_kind(&ident)),
+                "Pubkey" => IdlTypeNode::Primitive("pubkey"),
+                "String" => IdlTypeNode::Primitive("string"),
+                _ => IdlTypeNode::Defined(ident),
+            }
+        }
+        _ => IdlTypeNode::Defined(type_to_string(ty)),
+    }
+}
+
+fn primitive_kind(ident: &str) -> &'static str {
+    match ident {
+        "bool" => "bool",
+        "u8" => "u8",
+        "i8" => "i8",
+        "u16" => "u16",
+        "i16" => "i16",
+        "u32" => "u32",
+        "i32" => "i32",
+        "u64" => "u64",
+        "i64" => "i64",
+        "u128" => "u128",
+        "i128" => "i128",
+        _ => unreachable!(),
+    }
+}
+
 fn type_to_string(ty: &syn::Type) -> String {
     quote::quote!(#ty).to_string()
 }
 
-fn collect_accounts(ast: &File) -> Vec<IdlAccount> {
+fn collect_accounts(ast: &File, include_docs: bool) -> Vec<IdlAccount> {
     let mut accounts = Vec::new();
 
     for item in &ast.items {
         if let Item::Struct(item_struct) = item {
             if is_account_struct(item_struct) {
-                accounts.push(parse_account_struct(item_struct));
+                accounts.push(parse_account_struct(item_struct, include_docs));
             }
         }
     }
@@ -194,37 +816,45 @@ fn is_account_struct(item_struct: &syn::ItemStruct) -> bool {
     })
 }
 
-fn parse_account_struct(item_struct: &syn::ItemStruct) -> IdlAccount {
+fn parse_account_struct(item_struct: &syn::ItemStruct, include_docs: bool) -> IdlAccount {
     let name = item_struct.ident.to_string();
     let fields = item_struct
         .fields
         .iter()
         .filter_map(|field| {
             let field_name = field.ident.as_ref()?.to_string();
-            let type_name = type_to_string(&field.ty);
-            Some(IdlField { name: field_name, type_name })
+            let type_name = type_to_idl_type_node(&field.ty);
+            let docs = doc_opt(&field.attrs, include_docs);
+            Some(IdlField { name: field_name, type_name, docs })
         })
         .collect();
 
     IdlAccount {
         name: name.clone(),
         type_name: name,
+        docs: doc_opt(&item_struct.attrs, include_docs),
         fields,
     }
 }
 
-fn collect_types(ast: &File) -> Vec<IdlType> {
+/// `error_enum_name`, if given, excludes the program's error enum from the
+/// returned types by name rather than by checking `ast` for `#[error_code]`
+/// directly — `cargo expand` strips that attribute, so by the time `ast`
+/// exists the name has to come from the un-expanded source instead.
+fn collect_types(ast: &File, include_docs: bool, error_enum_name: Option<&str>) -> Vec<IdlType> {
     let mut types = Vec::new();
 
     for item in &ast.items {
         match item {
             Item::Struct(item_struct) => {
                 if !is_account_struct(item_struct) {
-                    types.push(parse_type_struct(item_struct));
+                    types.push(parse_type_struct(item_struct, include_docs));
                 }
             }
             Item::Enum(item_enum) => {
-                types.push(parse_type_enum(item_enum));
+                if error_enum_name != Some(item_enum.ident.to_string().as_str()) {
+                    types.push(parse_type_enum(item_enum, include_docs));
+                }
             }
             _ => {}
         }
@@ -233,18 +863,133 @@ fn collect_types(ast: &File) -> Vec<IdlType> {
     types
 }
 
-fn parse_type_struct(item_struct: &syn::ItemStruct) -> IdlType {
+fn parse_type_struct(item_struct: &syn::ItemStruct, include_docs: bool) -> IdlType {
     let name = item_struct.ident.to_string();
     let type_def = quote::quote!(#item_struct).to_string();
 
-    IdlType { name, type_def }
+    IdlType {
+        name,
+        type_def,
+        docs: doc_opt(&item_struct.attrs, include_docs),
+    }
 }
 
-fn parse_type_enum(item_enum: &syn::ItemEnum) -> IdlType {
+fn parse_type_enum(item_enum: &syn::ItemEnum, include_docs: bool) -> IdlType {
     let name = item_enum.ident.to_string();
     let type_def = quote::quote!(#item_enum).to_string();
 
-    IdlType { name, type_def }
+    IdlType {
+        name,
+        type_def,
+        docs: doc_opt(&item_enum.attrs, include_docs),
+    }
+}
+
+fn is_error_code_enum(item_enum: &syn::ItemEnum) -> bool {
+    item_enum
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("error_code"))
+}
+
+/// Finds the `#[error_code]` enum's name in the un-expanded source, so
+/// `collect_types` can exclude it by name from the expanded `ast` it
+/// actually walks, where the attribute no longer exists to check directly.
+fn error_enum_name(source_ast: &File) -> Option<String> {
+    source_ast.items.iter().find_map(|item| match item {
+        Item::Enum(item_enum) if is_error_code_enum(item_enum) => {
+            Some(item_enum.ident.to_string())
+        }
+        _ => None,
+    })
+}
+
+/// Finds the program's `#[error_code]` enum and assigns each variant a stable
+/// code starting at Anchor's `ERROR_CODE_OFFSET` (6000), in declaration order.
+///
+/// `ast` must be the *un-expanded* source: `#[error_code]` is an attribute
+/// macro, so `cargo expand` consumes both it and every `#[msg("...")]` on the
+/// enum's variants before re-emitting the enum, leaving nothing here to find.
+fn collect_errors(ast: &File) -> Vec<IdlErrorCode> {
+    let Some(item_enum) = ast.items.iter().find_map(|item| match item {
+        Item::Enum(item_enum) if is_error_code_enum(item_enum) => Some(item_enum),
+        _ => None,
+    }) else {
+        return Vec::new();
+    };
+
+    const ERROR_CODE_OFFSET: u32 = 6000;
+
+    item_enum
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| {
+            let msg = variant_msg_attr(&variant.attrs)
+                .or_else(|| {
+                    let docs = extract_doc_lines(&variant.attrs);
+                    (!docs.is_empty()).then(|| docs.join("\n"))
+                });
+
+            IdlErrorCode {
+                code: ERROR_CODE_OFFSET + index as u32,
+                name: variant.ident.to_string(),
+                msg,
+            }
+        })
+        .collect()
+}
+
+fn variant_msg_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("msg") {
+            return None;
+        }
+        attr.parse_args::<syn::LitStr>().ok().map(|lit| lit.value())
+    })
+}
+
+/// Collects `#[doc = "..."]` attributes (the AST form of `///` comments),
+/// stripping the single leading space Rust inserts and dropping empty
+/// trailing lines.
+fn extract_doc_lines(attrs: &[syn::Attribute]) -> Vec<String> {
+    let mut lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| {
+            if !attr.path().is_ident("doc") {
+                return None;
+            }
+            let Meta::NameValue(name_value) = &attr.meta else {
+                return None;
+            };
+            let syn::Expr::Lit(expr_lit) = &name_value.value else {
+                return None;
+            };
+            let syn::Lit::Str(lit_str) = &expr_lit.lit else {
+                return None;
+            };
+            Some(lit_str.value().strip_prefix(' ').map_or_else(
+                || lit_str.value(),
+                |stripped| stripped.to_string(),
+            ))
+        })
+        .collect();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+/// Collects doc comments unless `--no-docs` disabled them, returning `None`
+/// rather than an empty `Vec` so the field is omitted from the output.
+fn doc_opt(attrs: &[syn::Attribute], include_docs: bool) -> Option<Vec<String>> {
+    if !include_docs {
+        return None;
+    }
+    let docs = extract_doc_lines(attrs);
+    (!docs.is_empty()).then_some(docs)
 }
 
 fn extract_program_name(manifest_path: &PathBuf) -> Result<String, Box<dyn std::error::Error>> {
@@ -263,3 +1008,364 @@ fn extract_program_name(manifest_path: &PathBuf) -> Result<String, Box<dyn std::
 
     Ok(name.to_string())
 }
+
+/// Emits a `.ts` file mirroring the JSON IDL shape: a structural type named
+/// after the program (in PascalCase) plus the runtime `IDL` const, so a
+/// `@coral-xyz/anchor`-style client gets both from one generated file.
+fn generate_typescript(idl: &Idl, idl_json: &str) -> String {
+    let type_name = to_pascal_case(&idl.name);
+
+    format!(
+        r#"export type IdlType =
+  | {{ kind: "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128" | "pubkey" | "string" }}
+  | {{ vec: IdlType }}
+  | {{ option: IdlType }}
+  | {{ array: [IdlType, number] }}
+  | {{ defined: string }};
+
+export type IdlSeed =
+  | {{ kind: "const"; value: number[] }}
+  | {{ kind: "account"; path: string }}
+  | {{ kind: "arg"; path: string }}
+  | {{ kind: "program" }};
+
+export type IdlPda = {{
+  seeds: IdlSeed[];
+  program?: IdlSeed;
+}};
+
+export type {type_name} = {{
+  version: string;
+  name: string;
+  instructions: {{
+    name: string;
+    docs?: string[];
+    args: {{ name: string; type: IdlType; docs?: string[] }}[];
+    accounts: {{ name: string; is_mut: boolean; is_signer: boolean; pda?: IdlPda }}[];
+  }}[];
+  accounts: {{
+    name: string;
+    type_name: string;
+    docs?: string[];
+    fields: {{ name: string; type: IdlType; docs?: string[] }}[];
+  }}[];
+  types: {{ name: string; type_def: string; docs?: string[] }}[];
+  errors?: {{ code: number; name: string; msg?: string }}[];
+  docs?: string[];
+}};
+
+export const IDL: {type_name} = {idl_json};
+"#,
+        type_name = type_name,
+        idl_json = idl_json,
+    )
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accounts_struct_fields_flattens_composite_accounts() {
+        let inner: ItemStruct = syn::parse_str(
+            "pub struct Inner<'info> { #[account(mut)] pub vault: AccountInfo<'info> }",
+        )
+        .unwrap();
+        let outer: ItemStruct = syn::parse_str(
+            "pub struct Outer<'info> { pub inner: Inner<'info>, pub authority: Signer<'info> }",
+        )
+        .unwrap();
+        let mut account_structs = HashMap::new();
+        account_structs.insert("Inner".to_string(), &inner);
+
+        let metas = parse_accounts_struct_fields(&outer, &account_structs, &[]);
+
+        assert_eq!(metas.len(), 2, "composite field should flatten into its own fields");
+        assert_eq!(metas[0].name, "vault");
+        assert!(metas[0].is_mut);
+        assert!(!metas[0].is_signer);
+        assert_eq!(metas[1].name, "authority");
+        assert!(!metas[1].is_mut);
+        assert!(metas[1].is_signer, "Signer<'info> fields are signers even without #[account(signer)]");
+    }
+
+    #[test]
+    fn field_account_meta_detects_mut_and_signer_markers() {
+        let item_struct: ItemStruct = syn::parse_str(
+            "pub struct S<'info> { #[account(mut, signer)] pub authority: AccountInfo<'info> }",
+        )
+        .unwrap();
+        let field = item_struct.fields.into_iter().next().unwrap();
+
+        let flags = field_account_meta(&field);
+
+        assert!(flags.is_mut);
+        assert!(flags.is_signer);
+    }
+
+    #[test]
+    fn field_account_meta_defaults_to_unset_without_constraints() {
+        let item_struct: ItemStruct =
+            syn::parse_str("pub struct S<'info> { pub authority: AccountInfo<'info> }").unwrap();
+        let field = item_struct.fields.into_iter().next().unwrap();
+
+        let flags = field_account_meta(&field);
+
+        assert!(!flags.is_mut);
+        assert!(!flags.is_signer);
+    }
+
+    #[test]
+    fn field_type_is_signer_checks_the_signer_type_only() {
+        assert!(field_type_is_signer(&syn::parse_str("Signer<'info>").unwrap()));
+        assert!(!field_type_is_signer(
+            &syn::parse_str("AccountInfo<'info>").unwrap()
+        ));
+    }
+
+    #[test]
+    fn field_pda_resolves_seeds_sharing_an_attribute_with_mut() {
+        let item_struct: ItemStruct = syn::parse_str(
+            "pub struct S<'info> { #[account(mut, seeds = [b\"vault\", authority.key().as_ref()], bump)] pub vault: AccountInfo<'info> }",
+        )
+        .unwrap();
+        let field = item_struct.fields.into_iter().next().unwrap();
+
+        let flags = field_account_meta(&field);
+        let pda = field_pda(&field, &["authority".to_string()], &[]);
+
+        assert!(
+            flags.is_mut,
+            "mut must still be recognized when it shares an attribute with seeds"
+        );
+        let pda = pda.expect("seeds sharing the attribute with mut should still be found");
+        assert_eq!(
+            pda.seeds,
+            vec![
+                IdlSeed::Const { value: b"vault".to_vec() },
+                IdlSeed::Account { path: "authority".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_errors_assigns_codes_from_error_code_offset_in_declaration_order() {
+        let ast: File = syn::parse_str(
+            r#"
+            #[error_code]
+            pub enum MyError {
+                #[msg("not authorized")]
+                Unauthorized,
+                InsufficientFunds,
+            }
+            "#,
+        )
+        .unwrap();
+
+        let errors = collect_errors(&ast);
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].code, 6000);
+        assert_eq!(errors[0].name, "Unauthorized");
+        assert_eq!(errors[0].msg.as_deref(), Some("not authorized"));
+        assert_eq!(errors[1].code, 6001);
+        assert_eq!(errors[1].name, "InsufficientFunds");
+        assert_eq!(errors[1].msg, None);
+    }
+
+    #[test]
+    fn collect_errors_falls_back_to_doc_comments_without_a_msg_attr() {
+        let ast: File = syn::parse_str(
+            r#"
+            #[error_code]
+            pub enum MyError {
+                /// the vault is frozen
+                VaultFrozen,
+            }
+            "#,
+        )
+        .unwrap();
+
+        let errors = collect_errors(&ast);
+
+        assert_eq!(errors[0].msg.as_deref(), Some("the vault is frozen"));
+    }
+
+    #[test]
+    fn collect_errors_returns_empty_without_an_error_code_enum() {
+        let ast: File = syn::parse_str("pub enum MyError { Unauthorized }").unwrap();
+
+        assert!(collect_errors(&ast).is_empty());
+    }
+
+    #[test]
+    fn error_enum_name_finds_the_error_code_enum_in_unexpanded_source() {
+        let source_ast: File = syn::parse_str(
+            r#"
+            pub enum Other {}
+            #[error_code]
+            pub enum MyError { Unauthorized }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(error_enum_name(&source_ast).as_deref(), Some("MyError"));
+    }
+
+    #[test]
+    fn error_enum_name_is_none_without_an_error_code_enum() {
+        let source_ast: File = syn::parse_str("pub enum Other {}").unwrap();
+
+        assert_eq!(error_enum_name(&source_ast), None);
+    }
+
+    #[test]
+    fn collect_types_excludes_the_error_enum_by_name_from_expanded_ast() {
+        // Simulates the post-`cargo expand` tree, where `#[error_code]` has
+        // already been stripped — `collect_types` must rely on the name
+        // passed in from the un-expanded source, not on the attribute.
+        let expanded_ast: File = syn::parse_str(
+            r#"
+            pub enum MyError { Unauthorized }
+            pub struct Settings { pub admin: Pubkey }
+            "#,
+        )
+        .unwrap();
+
+        let types = collect_types(&expanded_ast, false, Some("MyError"));
+
+        assert_eq!(types.len(), 1, "the error enum must not leak into idl.types");
+        assert_eq!(types[0].name, "Settings");
+    }
+
+    #[test]
+    fn collect_types_keeps_enums_when_no_error_enum_name_is_given() {
+        let ast: File = syn::parse_str("pub enum MyError { Unauthorized }").unwrap();
+
+        let types = collect_types(&ast, false, None);
+
+        assert_eq!(types.len(), 1);
+        assert_eq!(types[0].name, "MyError");
+    }
+
+    fn idl_type_node_of(ty: &str) -> IdlTypeNode {
+        type_to_idl_type_node(&syn::parse_str::<Type>(ty).unwrap())
+    }
+
+    #[test]
+    fn type_to_idl_type_node_resolves_primitives() {
+        assert_eq!(idl_type_node_of("u64"), IdlTypeNode::Primitive("u64"));
+        assert_eq!(idl_type_node_of("bool"), IdlTypeNode::Primitive("bool"));
+        assert_eq!(idl_type_node_of("Pubkey"), IdlTypeNode::Primitive("pubkey"));
+        assert_eq!(idl_type_node_of("String"), IdlTypeNode::Primitive("string"));
+    }
+
+    #[test]
+    fn type_to_idl_type_node_resolves_containers() {
+        assert_eq!(
+            idl_type_node_of("Vec<u8>"),
+            IdlTypeNode::Vec(Box::new(IdlTypeNode::Primitive("u8")))
+        );
+        assert_eq!(
+            idl_type_node_of("Option<Pubkey>"),
+            IdlTypeNode::Option(Box::new(IdlTypeNode::Primitive("pubkey")))
+        );
+        assert_eq!(
+            idl_type_node_of("&u64"),
+            IdlTypeNode::Primitive("u64"),
+            "references should be peeled"
+        );
+    }
+
+    #[test]
+    fn type_to_idl_type_node_resolves_array_with_literal_length() {
+        assert_eq!(
+            idl_type_node_of("[u8; 32]"),
+            IdlTypeNode::Array(Box::new(IdlTypeNode::Primitive("u8")), 32)
+        );
+    }
+
+    #[test]
+    fn type_to_idl_type_node_falls_back_to_zero_length_for_non_literal_array_lengths() {
+        assert_eq!(
+            idl_type_node_of("[u8; N]"),
+            IdlTypeNode::Array(Box::new(IdlTypeNode::Primitive("u8")), 0)
+        );
+    }
+
+    #[test]
+    fn type_to_idl_type_node_resolves_defined_types() {
+        assert_eq!(
+            idl_type_node_of("MyStruct"),
+            IdlTypeNode::Defined("MyStruct".to_string())
+        );
+    }
+
+    fn seed_of(expr: &str, account_names: &[&str], arg_names: &[&str]) -> Option<IdlSeed> {
+        let account_names: Vec<String> = account_names.iter().map(|s| s.to_string()).collect();
+        let arg_names: Vec<String> = arg_names.iter().map(|s| s.to_string()).collect();
+        seed_expr_to_idl_seed(
+            &syn::parse_str::<syn::Expr>(expr).unwrap(),
+            &account_names,
+            &arg_names,
+        )
+    }
+
+    #[test]
+    fn seed_expr_to_idl_seed_resolves_byte_string_const() {
+        assert_eq!(
+            seed_of(r#"b"vault""#, &[], &[]),
+            Some(IdlSeed::Const {
+                value: b"vault".to_vec()
+            })
+        );
+    }
+
+    #[test]
+    fn seed_expr_to_idl_seed_resolves_byte_array_const() {
+        assert_eq!(
+            seed_of("[1, 2, 3]", &[], &[]),
+            Some(IdlSeed::Const { value: vec![1, 2, 3] })
+        );
+    }
+
+    #[test]
+    fn seed_expr_to_idl_seed_resolves_account_and_arg_paths() {
+        assert_eq!(
+            seed_of("authority.key().as_ref()", &["authority"], &[]),
+            Some(IdlSeed::Account {
+                path: "authority".to_string()
+            })
+        );
+        assert_eq!(
+            seed_of("seed_id.as_ref()", &[], &["seed_id"]),
+            Some(IdlSeed::Arg {
+                path: "seed_id".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn seed_expr_to_idl_seed_resolves_program_id_idioms() {
+        assert_eq!(seed_of("crate::ID", &[], &[]), Some(IdlSeed::Program));
+        assert_eq!(seed_of("Token::id()", &[], &[]), Some(IdlSeed::Program));
+    }
+
+    #[test]
+    fn seed_expr_to_idl_seed_drops_unrecognized_names() {
+        assert_eq!(seed_of("unknown_thing", &["authority"], &["seed_id"]), None);
+    }
+}